@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+
+/// Arguments for the `symbols` command, which lists workspace symbols
+/// discovered by `ty_ide::workspace_symbols`.
+#[derive(Debug, Args)]
+pub(crate) struct SymbolsArgs {
+    /// The symbol name (or substring) to search for. Matches everything
+    /// when omitted.
+    #[arg(default_value = "")]
+    pub(crate) query: String,
+
+    /// Restrict results to one or more symbol kinds.
+    #[arg(long = "kind", value_enum)]
+    pub(crate) kinds: Vec<SymbolKindFilter>,
+
+    /// Maximum number of results to print.
+    #[arg(long)]
+    pub(crate) limit: Option<usize>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = SymbolsFormat::Human)]
+    pub(crate) format: SymbolsFormat,
+
+    /// Path to the Python interpreter to use for environment discovery.
+    ///
+    /// Takes precedence over the `TY_PYTHON`/`VIRTUAL_ENV` environment
+    /// variables and auto-discovery of a `.venv` under the project root.
+    #[arg(long)]
+    pub(crate) python: Option<PathBuf>,
+}
+
+/// Symbol kinds that `--kind` can filter on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum SymbolKindFilter {
+    Function,
+    Method,
+    Class,
+    Variable,
+    Constant,
+    Module,
+}
+
+/// Output format for `symbols` results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub(crate) enum SymbolsFormat {
+    /// Human-readable, one result per line.
+    #[default]
+    Human,
+    /// A stable JSON array of `{name, kind, uri, range}` objects, shaped
+    /// like the LSP `workspace/symbol` response.
+    Json,
+}
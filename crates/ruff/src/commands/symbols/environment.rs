@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use ruff_db::system::SystemPathBuf;
+
+use super::error::ProjectSetupError;
+use super::interpreter::resolve_interpreter;
+
+/// The Python environment backing a `symbols` query.
+///
+/// In addition to first-party sources under the workspace root, symbol
+/// search should also see the interpreter's standard library and any
+/// installed third-party packages. This is what `probe_interpreter`
+/// resolves by actually asking the interpreter about itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PythonEnvironment {
+    pub(crate) prefix: PathBuf,
+    pub(crate) search_paths: Vec<SystemPathBuf>,
+    pub(crate) version: (u32, u32),
+}
+
+impl PythonEnvironment {
+    /// The interpreter's standard library directory, derived from
+    /// `prefix` and `version` using the usual CPython install layout.
+    pub(crate) fn stdlib_path(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.prefix.join("Lib")
+        } else {
+            self.prefix
+                .join("lib")
+                .join(format!("python{}.{}", self.version.0, self.version.1))
+        }
+    }
+
+    /// The interpreter's site-packages directory, derived the same way.
+    pub(crate) fn site_packages_path(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.prefix.join("Lib").join("site-packages")
+        } else {
+            self.stdlib_path().join("site-packages")
+        }
+    }
+
+    /// The extra search paths this environment contributes, in the shape
+    /// `ty_project`'s `[environment] extra-paths` option expects. Search
+    /// paths are applied to the project metadata through `Options`, not
+    /// through a dedicated setter.
+    ///
+    /// `sys.path` already lists site-packages in the common case, but the
+    /// derived `site_packages_path` is added explicitly in case a
+    /// non-standard `sys.path` (e.g. `-S`, a frozen interpreter) omits it.
+    pub(crate) fn extra_paths(&self) -> Vec<SystemPathBuf> {
+        let mut paths = self.search_paths.clone();
+        if let Ok(site_packages) = SystemPathBuf::from_path_buf(self.site_packages_path()) {
+            if !paths.contains(&site_packages) {
+                paths.push(site_packages);
+            }
+        }
+        paths
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct InterpreterProbeOutput {
+    prefix: PathBuf,
+    path: Vec<PathBuf>,
+    version: (u32, u32),
+}
+
+/// On-disk cache of interpreter probe results, keyed by interpreter path
+/// and mtime.
+///
+/// `symbols` is a one-shot CLI invocation, so a process-local cache alone
+/// never pays off: the process exits right after printing results. This
+/// is persisted to a file so that *repeated shell invocations* skip
+/// re-spawning the interpreter, which is what the cache is actually for.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProbeCache {
+    entries: HashMap<String, PythonEnvironment>,
+}
+
+fn probe_cache_path() -> PathBuf {
+    std::env::var_os("TY_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("ty-symbols-interpreter-probe-cache.json")
+}
+
+fn probe_cache() -> &'static Mutex<ProbeCache> {
+    static CACHE: OnceLock<Mutex<ProbeCache>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        let cache = std::fs::read_to_string(probe_cache_path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        Mutex::new(cache)
+    })
+}
+
+fn save_probe_cache(cache: &ProbeCache) {
+    if let Ok(contents) = serde_json::to_string(cache) {
+        let _ = std::fs::write(probe_cache_path(), contents);
+    }
+}
+
+/// Cache key for `interpreter`: its path plus its mtime, so a recompiled
+/// or reinstalled interpreter at the same path invalidates the entry.
+fn cache_key(interpreter: &Path) -> String {
+    let mtime = std::fs::metadata(interpreter)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs());
+
+    match mtime {
+        Some(mtime) => format!("{}@{mtime}", interpreter.display()),
+        None => interpreter.display().to_string(),
+    }
+}
+
+/// Discover the Python environment that should back symbol resolution.
+///
+/// Precedence mirrors rust-analyzer's sysroot discovery: an explicit
+/// interpreter (typically resolved via `--python`) wins, then the
+/// `TY_PYTHON` environment variable, then [`resolve_interpreter`]'s own
+/// search (`$VIRTUAL_ENV`, `.venv`, pyenv, `$PATH`). When that search
+/// turns up no interpreter, `None` is returned and callers should fall
+/// back to the bundled typeshed stubs rather than failing outright. An
+/// explicit or `TY_PYTHON` interpreter that doesn't exist, or an
+/// interpreter whose probe fails, is returned as an error instead.
+pub(crate) fn discover_environment(
+    workspace_root: &Path,
+    explicit_interpreter: Option<&Path>,
+) -> Result<Option<PythonEnvironment>, ProjectSetupError> {
+    let ty_python = std::env::var_os("TY_PYTHON").map(PathBuf::from);
+    // Whether the user pointed at a specific interpreter, as opposed to
+    // letting us fall back to auto-discovery. This decides what happens
+    // when resolution comes up empty: a user-requested interpreter that
+    // can't be found is an error, but a workspace with no interpreter at
+    // all just falls back to the bundled typeshed stubs.
+    let user_requested = explicit_interpreter.is_some() || ty_python.is_some();
+    let explicit = explicit_interpreter.map(Path::to_path_buf).or(ty_python);
+
+    let interpreter = match resolve_interpreter(workspace_root, explicit.as_deref()) {
+        Ok(resolved) => resolved.path,
+        Err(error) if user_requested => return Err(error),
+        Err(_) => return Ok(None),
+    };
+
+    probe_interpreter(&interpreter).map(Some)
+}
+
+/// Invoke `interpreter` once to learn its `sys.prefix`, `sys.path`, and
+/// version, caching the result on disk by interpreter path and mtime so
+/// that repeated `symbols` invocations don't re-spawn the process.
+///
+/// Any failure here — the interpreter can't be spawned, it doesn't speak
+/// JSON back, or its `sys.path` doesn't contain a recognizable standard
+/// library — means we can't tell where this interpreter's stdlib lives,
+/// which is exactly the `StdlibNotFound` case.
+fn probe_interpreter(interpreter: &Path) -> Result<PythonEnvironment, ProjectSetupError> {
+    let key = cache_key(interpreter);
+
+    if let Some(cached) = probe_cache().lock().unwrap().entries.get(&key) {
+        return Ok(cached.clone());
+    }
+
+    let not_found = || ProjectSetupError::StdlibNotFound {
+        interpreter: interpreter.to_path_buf(),
+    };
+
+    let output = Command::new(interpreter)
+        .arg("-c")
+        .arg(
+            "import sys, json; print(json.dumps({'prefix': sys.prefix, 'path': sys.path, 'version': sys.version_info[:2]}))",
+        )
+        .output()
+        .map_err(|_| not_found())?;
+
+    if !output.status.success() {
+        return Err(not_found());
+    }
+
+    let parsed: InterpreterProbeOutput =
+        serde_json::from_slice(&output.stdout).map_err(|_| not_found())?;
+
+    let search_paths: Vec<SystemPathBuf> = parsed
+        .path
+        .into_iter()
+        .filter_map(|path| SystemPathBuf::from_path_buf(path).ok())
+        .collect();
+
+    let environment = PythonEnvironment {
+        prefix: parsed.prefix,
+        search_paths,
+        version: parsed.version,
+    };
+
+    if !has_stdlib_marker(&environment) {
+        return Err(not_found());
+    }
+
+    let mut cache = probe_cache().lock().unwrap();
+    cache.entries.insert(key, environment.clone());
+    save_probe_cache(&cache);
+
+    Ok(environment)
+}
+
+/// Whether `environment` has a locatable standard library: either the
+/// derived `stdlib_path`, or — for layouts that don't follow the usual
+/// `prefix/lib/pythonX.Y` convention — any `sys.path` entry containing
+/// `os.py` (present in every CPython stdlib).
+fn has_stdlib_marker(environment: &PythonEnvironment) -> bool {
+    environment.stdlib_path().join("os.py").is_file()
+        || environment
+            .search_paths
+            .iter()
+            .any(|path| path.as_std_path().join("os.py").is_file())
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::ExitStatus;
+
+/// Errors that can occur while setting up a project for a `symbols`
+/// query.
+///
+/// Each variant carries the offending path and a suggested fix, so that
+/// a bad project root or a missing environment prints an actionable
+/// message instead of a panic or a bare `anyhow` backtrace.
+#[derive(Debug)]
+pub(crate) enum ProjectSetupError {
+    /// The resolved project root isn't an absolute, valid path.
+    InvalidProjectRoot { path: PathBuf },
+    /// No Python interpreter could be found anywhere that was searched.
+    InterpreterNotFound { searched: Vec<String> },
+    /// An interpreter was found, but probing it did not locate its
+    /// standard library.
+    StdlibNotFound { interpreter: PathBuf },
+    /// A `ty-project.json` manifest exists but failed to parse.
+    ManifestParseFailure { path: PathBuf, reason: String },
+    /// `pyproject.toml`/CLI configuration could not be resolved.
+    ConfigResolutionFailure { reason: String },
+}
+
+impl fmt::Display for ProjectSetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidProjectRoot { path } => write!(
+                f,
+                "`{}` is not a valid project root: it must be an absolute path \
+                 that exists on disk",
+                path.display()
+            ),
+            Self::InterpreterNotFound { searched } => write!(
+                f,
+                "could not find a Python interpreter; searched:\n{}\n\n\
+                 try creating a virtual environment with `python -m venv .venv`",
+                searched
+                    .iter()
+                    .map(|location| format!("  - {location}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+            Self::StdlibNotFound { interpreter } => write!(
+                f,
+                "could not locate the standard library for `{}`; try reinstalling \
+                 the interpreter or passing `--python` to select a different one",
+                interpreter.display()
+            ),
+            Self::ManifestParseFailure { path, reason } => write!(
+                f,
+                "failed to parse `{}`: {reason}\n\n\
+                 check that it is valid JSON matching the ty-project.json schema",
+                path.display()
+            ),
+            Self::ConfigResolutionFailure { reason } => write!(
+                f,
+                "failed to resolve project configuration: {reason}\n\n\
+                 check `pyproject.toml` and any `--config` overrides for errors"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProjectSetupError {}
+
+impl ProjectSetupError {
+    /// Print this error with its remediation hint and return the
+    /// `ExitStatus` the CLI should exit with.
+    ///
+    /// Every variant exits with `ExitStatus::Error`; what differs per
+    /// variant is the message and remediation hint, which would otherwise
+    /// be lost in an opaque `anyhow::Error`.
+    pub(crate) fn report(&self) -> ExitStatus {
+        eprintln!("error: {self}");
+        ExitStatus::Error
+    }
+}
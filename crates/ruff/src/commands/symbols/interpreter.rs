@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use super::error::ProjectSetupError;
+
+/// How a Python interpreter was located.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InterpreterSource {
+    /// Passed explicitly, e.g. via `--python`.
+    Explicit,
+    /// `$VIRTUAL_ENV/bin/python`.
+    VirtualEnv,
+    /// A `.venv` directory under the project root.
+    DotVenv,
+    /// A pyenv shim under `~/.pyenv/shims`.
+    Pyenv,
+    /// Found on `$PATH`.
+    Path,
+}
+
+/// The result of resolving a Python interpreter: where it lives, and how
+/// it was found (useful for diagnostics and for cache-keying the
+/// interpreter probe).
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedInterpreter {
+    pub(crate) path: PathBuf,
+    pub(crate) source: InterpreterSource,
+}
+
+/// Resolve the Python interpreter that should back a `symbols` query.
+///
+/// Checked in order, mirroring rust-analyzer's `get_path_for_executable`:
+/// an explicit `--python` argument, `$VIRTUAL_ENV/bin/python`, a `.venv`
+/// directory under `project_root`, pyenv shims, and finally `$PATH`. When
+/// none of these resolve to an interpreter, an actionable error lists
+/// every location that was searched.
+///
+/// An explicit interpreter (passed by the caller, e.g. from `--python` or
+/// `TY_PYTHON`) is never allowed to silently fall through to the other
+/// candidates: if it doesn't exist, that's an error, not a cue to go
+/// looking elsewhere for an interpreter the user didn't ask for.
+pub(crate) fn resolve_interpreter(
+    project_root: &Path,
+    explicit: Option<&Path>,
+) -> Result<ResolvedInterpreter, ProjectSetupError> {
+    if let Some(explicit) = explicit {
+        return if explicit.is_file() {
+            Ok(ResolvedInterpreter {
+                path: explicit.to_path_buf(),
+                source: InterpreterSource::Explicit,
+            })
+        } else {
+            Err(ProjectSetupError::InterpreterNotFound {
+                searched: vec![explicit.display().to_string()],
+            })
+        };
+    }
+
+    let mut searched = Vec::new();
+
+    if let Some(venv) = std::env::var_os("VIRTUAL_ENV") {
+        let candidate = venv_python(Path::new(&venv));
+        if candidate.is_file() {
+            return Ok(ResolvedInterpreter {
+                path: candidate,
+                source: InterpreterSource::VirtualEnv,
+            });
+        }
+        searched.push(candidate.display().to_string());
+    }
+
+    let dot_venv = venv_python(&project_root.join(".venv"));
+    if dot_venv.is_file() {
+        return Ok(ResolvedInterpreter {
+            path: dot_venv,
+            source: InterpreterSource::DotVenv,
+        });
+    }
+    searched.push(dot_venv.display().to_string());
+
+    if let Some(home) = dirs_home() {
+        let shim = home
+            .join(".pyenv")
+            .join("shims")
+            .join(if cfg!(windows) { "python.exe" } else { "python" });
+        if shim.is_file() {
+            return Ok(ResolvedInterpreter {
+                path: shim,
+                source: InterpreterSource::Pyenv,
+            });
+        }
+        searched.push(shim.display().to_string());
+    }
+
+    if let Some(path) = which("python").or_else(|| which("python3")) {
+        return Ok(ResolvedInterpreter {
+            path,
+            source: InterpreterSource::Path,
+        });
+    }
+    searched.push("python/python3 on $PATH".to_string());
+
+    Err(ProjectSetupError::InterpreterNotFound { searched })
+}
+
+fn venv_python(venv: &Path) -> PathBuf {
+    if cfg!(windows) {
+        venv.join("Scripts").join("python.exe")
+    } else {
+        venv.join("bin").join("python")
+    }
+}
+
+fn dirs_home() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+fn which(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `resolve_interpreter` reads `$VIRTUAL_ENV`/`$PATH`/`$HOME`, so tests
+    // that set them must not run concurrently with each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn touch_executable(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn explicit_interpreter_wins() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let python = dir.path().join("python");
+        touch_executable(&python);
+
+        let resolved = resolve_interpreter(dir.path(), Some(&python)).unwrap();
+        assert_eq!(resolved.path, python);
+        assert_eq!(resolved.source, InterpreterSource::Explicit);
+    }
+
+    #[test]
+    fn explicit_interpreter_missing_errors_immediately() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("does-not-exist");
+
+        let error = resolve_interpreter(dir.path(), Some(&missing)).unwrap_err();
+        assert!(matches!(error, ProjectSetupError::InterpreterNotFound { .. }));
+    }
+
+    #[test]
+    fn falls_back_to_dot_venv_when_no_virtual_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("VIRTUAL_ENV");
+
+        let dir = tempfile::tempdir().unwrap();
+        let dot_venv_python = venv_python(&dir.path().join(".venv"));
+        touch_executable(&dot_venv_python);
+
+        let resolved = resolve_interpreter(dir.path(), None).unwrap();
+        assert_eq!(resolved.path, dot_venv_python);
+        assert_eq!(resolved.source, InterpreterSource::DotVenv);
+    }
+
+    #[test]
+    fn virtual_env_takes_precedence_over_dot_venv() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let project_dir = tempfile::tempdir().unwrap();
+        let dot_venv_python = venv_python(&project_dir.path().join(".venv"));
+        touch_executable(&dot_venv_python);
+
+        let venv_dir = tempfile::tempdir().unwrap();
+        let virtual_env_python = venv_python(venv_dir.path());
+        touch_executable(&virtual_env_python);
+
+        std::env::set_var("VIRTUAL_ENV", venv_dir.path());
+        let resolved = resolve_interpreter(project_dir.path(), None);
+        std::env::remove_var("VIRTUAL_ENV");
+
+        let resolved = resolved.unwrap();
+        assert_eq!(resolved.path, virtual_env_python);
+        assert_eq!(resolved.source, InterpreterSource::VirtualEnv);
+    }
+
+    #[test]
+    fn broken_virtual_env_falls_through_to_dot_venv() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let project_dir = tempfile::tempdir().unwrap();
+        let dot_venv_python = venv_python(&project_dir.path().join(".venv"));
+        touch_executable(&dot_venv_python);
+
+        // `$VIRTUAL_ENV` points somewhere with no `bin/python` in it.
+        let empty_venv_dir = tempfile::tempdir().unwrap();
+        std::env::set_var("VIRTUAL_ENV", empty_venv_dir.path());
+        let resolved = resolve_interpreter(project_dir.path(), None);
+        std::env::remove_var("VIRTUAL_ENV");
+
+        let resolved = resolved.unwrap();
+        assert_eq!(resolved.path, dot_venv_python);
+        assert_eq!(resolved.source, InterpreterSource::DotVenv);
+    }
+
+    #[test]
+    fn errors_with_every_searched_location_when_nothing_found() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("VIRTUAL_ENV");
+        std::env::set_var("HOME", tempfile::tempdir().unwrap().path());
+        std::env::set_var("PATH", "");
+
+        let dir = tempfile::tempdir().unwrap();
+        let error = resolve_interpreter(dir.path(), None).unwrap_err();
+
+        match error {
+            ProjectSetupError::InterpreterNotFound { searched } => {
+                assert_eq!(searched.len(), 3);
+            }
+            other => panic!("expected InterpreterNotFound, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use ruff_db::system::SystemPathBuf;
+use ruff_python_ast::name::Name;
+use ty_project::metadata::options::{Options, SrcOptions};
+use ty_project::ProjectMetadata;
+
+use super::error::ProjectSetupError;
+
+const MANIFEST_FILE_NAME: &str = "ty-project.json";
+
+/// A machine-readable description of a project's source roots and
+/// dependency graph, analogous to rust-analyzer's `rust-project.json`.
+///
+/// Build systems that don't produce a `pyproject.toml` (Bazel, Pants, ...)
+/// can emit one of these next to the workspace root so that `symbols`
+/// indexes exactly the intended files instead of guessing.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProjectManifest {
+    pub(crate) roots: Vec<SourceRoot>,
+    /// Extra paths to search for third-party packages, in addition to
+    /// whatever the discovered Python environment contributes.
+    #[serde(default)]
+    pub(crate) search_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SourceRoot {
+    pub(crate) name: String,
+    pub(crate) path: PathBuf,
+    #[serde(default)]
+    pub(crate) include: Vec<String>,
+    #[serde(default)]
+    pub(crate) exclude: Vec<String>,
+    /// Names of other roots this root depends on.
+    #[serde(default)]
+    pub(crate) depends_on: Vec<String>,
+}
+
+/// Look for a `ty-project.json` manifest at `workspace_root` and load it
+/// if present. Returns `Ok(None)` when no manifest exists so callers can
+/// fall back to auto-discovery.
+pub(crate) fn load_manifest(
+    workspace_root: &Path,
+) -> Result<Option<ProjectManifest>, ProjectSetupError> {
+    let manifest_path = workspace_root.join(MANIFEST_FILE_NAME);
+    if !manifest_path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&manifest_path).map_err(|err| {
+        ProjectSetupError::ManifestParseFailure {
+            path: manifest_path.clone(),
+            reason: err.to_string(),
+        }
+    })?;
+    let manifest: ProjectManifest =
+        serde_json::from_str(&contents).map_err(|err| ProjectSetupError::ManifestParseFailure {
+            path: manifest_path.clone(),
+            reason: err.to_string(),
+        })?;
+
+    Ok(Some(manifest))
+}
+
+/// Build `ProjectMetadata` from an explicit manifest instead of inferring
+/// a single project rooted at `workspace_root`.
+///
+/// `ProjectMetadata` still models a single project — there's no upstream
+/// concept of a Cargo-workspace-style collection of members — so every
+/// root's `include`/`exclude` globs are scoped under that root's own path
+/// and merged into one `src` option set, in dependency order. This is
+/// what makes the manifest actually narrow the indexed files instead of
+/// only renaming the project.
+pub(crate) fn project_metadata_from_manifest(
+    manifest: &ProjectManifest,
+    workspace_root: &Path,
+) -> Result<ProjectMetadata, ProjectSetupError> {
+    let manifest_path = workspace_root.join(MANIFEST_FILE_NAME);
+    let ordered = topological_order(&manifest.roots, &manifest_path)?;
+
+    let project_name = ordered
+        .first()
+        .map(|root| root.name.clone())
+        .unwrap_or_else(|| "ruff".to_string());
+
+    let root_path = SystemPathBuf::from_path_buf(workspace_root.to_path_buf()).map_err(|_| {
+        ProjectSetupError::InvalidProjectRoot {
+            path: workspace_root.to_path_buf(),
+        }
+    })?;
+
+    let mut metadata = ProjectMetadata::new(Name::new(project_name), root_path);
+
+    let mut include = Vec::new();
+    let mut exclude = Vec::new();
+    for root in &ordered {
+        include.extend(root_globs(root, &root.include, "**"));
+        exclude.extend(root_globs(root, &root.exclude, ""));
+    }
+
+    if !include.is_empty() || !exclude.is_empty() {
+        metadata.apply_cli_options(Options {
+            src: Some(SrcOptions {
+                include: (!include.is_empty()).then_some(include),
+                exclude: (!exclude.is_empty()).then_some(exclude),
+                ..SrcOptions::default()
+            }),
+            ..Options::default()
+        });
+    }
+
+    Ok(metadata)
+}
+
+/// Glob patterns for `root`, scoped under its own path. An empty
+/// `patterns` list defaults to `default_pattern` (the whole root for
+/// `include`; nothing for `exclude`).
+fn root_globs(root: &SourceRoot, patterns: &[String], default_pattern: &str) -> Vec<String> {
+    if patterns.is_empty() {
+        return if default_pattern.is_empty() {
+            Vec::new()
+        } else {
+            vec![root.path.join(default_pattern).display().to_string()]
+        };
+    }
+
+    patterns
+        .iter()
+        .map(|pattern| root.path.join(pattern).display().to_string())
+        .collect()
+}
+
+/// Order roots so that every root appears after the roots it
+/// `depends_on`, erroring out on an unknown or cyclic dependency.
+///
+/// Uses a three-state (unvisited / in-progress / done) walk: a root
+/// still `InProgress` when revisited is a back-edge — an actual cycle —
+/// distinct from a root that's already `Done`. A single `visited` set
+/// can't tell those apart and would silently accept the cycle.
+fn topological_order<'a>(
+    roots: &'a [SourceRoot],
+    manifest_path: &Path,
+) -> Result<Vec<&'a SourceRoot>, ProjectSetupError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        InProgress,
+        Done,
+    }
+
+    fn visit<'a>(
+        root: &'a SourceRoot,
+        roots: &'a [SourceRoot],
+        state: &mut HashMap<&'a str, State>,
+        ordered: &mut Vec<&'a SourceRoot>,
+        manifest_path: &Path,
+    ) -> Result<(), ProjectSetupError> {
+        match state.get(root.name.as_str()) {
+            Some(State::Done) => return Ok(()),
+            Some(State::InProgress) => {
+                return Err(ProjectSetupError::ManifestParseFailure {
+                    path: manifest_path.to_path_buf(),
+                    reason: format!("cyclic root dependency involving `{}`", root.name),
+                });
+            }
+            None => {}
+        }
+        state.insert(&root.name, State::InProgress);
+
+        for dependency_name in &root.depends_on {
+            let dependency = roots
+                .iter()
+                .find(|candidate| &candidate.name == dependency_name)
+                .ok_or_else(|| ProjectSetupError::ManifestParseFailure {
+                    path: manifest_path.to_path_buf(),
+                    reason: format!(
+                        "root `{}` depends on unknown root `{dependency_name}`",
+                        root.name
+                    ),
+                })?;
+            visit(dependency, roots, state, ordered, manifest_path)?;
+        }
+
+        state.insert(&root.name, State::Done);
+        ordered.push(root);
+        Ok(())
+    }
+
+    let mut state = HashMap::new();
+    let mut ordered = Vec::with_capacity(roots.len());
+
+    for root in roots {
+        visit(root, roots, &mut state, &mut ordered, manifest_path)?;
+    }
+
+    Ok(ordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(name: &str, depends_on: &[&str]) -> SourceRoot {
+        SourceRoot {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            depends_on: depends_on.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    fn order_of(roots: &[SourceRoot]) -> Result<Vec<&str>, ProjectSetupError> {
+        topological_order(roots, Path::new("ty-project.json"))
+            .map(|ordered| ordered.iter().map(|root| root.name.as_str()).collect())
+    }
+
+    #[test]
+    fn no_deps_preserves_input_order() {
+        let roots = vec![root("a", &[]), root("b", &[]), root("c", &[])];
+        assert_eq!(order_of(&roots).unwrap(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn linear_chain_orders_dependencies_first() {
+        let roots = vec![root("a", &["b"]), root("b", &["c"]), root("c", &[])];
+        assert_eq!(order_of(&roots).unwrap(), vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn diamond_visits_shared_dependency_once() {
+        // d <- b, d <- c, b <- a, c <- a
+        let roots = vec![
+            root("a", &["b", "c"]),
+            root("b", &["d"]),
+            root("c", &["d"]),
+            root("d", &[]),
+        ];
+        let ordered = order_of(&roots).unwrap();
+        assert_eq!(ordered.len(), 4);
+        assert_eq!(ordered.last(), Some(&"a"));
+        assert_eq!(ordered[0], "d");
+        assert!(ordered.iter().position(|name| *name == "b").unwrap() < 3);
+        assert!(ordered.iter().position(|name| *name == "c").unwrap() < 3);
+    }
+
+    #[test]
+    fn self_cycle_errors() {
+        let roots = vec![root("a", &["a"])];
+        let error = order_of(&roots).unwrap_err();
+        assert!(matches!(error, ProjectSetupError::ManifestParseFailure { .. }));
+    }
+
+    #[test]
+    fn longer_cycle_errors() {
+        let roots = vec![root("a", &["b"]), root("b", &["c"]), root("c", &["a"])];
+        let error = order_of(&roots).unwrap_err();
+        assert!(matches!(error, ProjectSetupError::ManifestParseFailure { .. }));
+    }
+
+    #[test]
+    fn unknown_dependency_errors() {
+        let roots = vec![root("a", &["missing"])];
+        let error = order_of(&roots).unwrap_err();
+        assert!(matches!(error, ProjectSetupError::ManifestParseFailure { .. }));
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use ruff_db::system::{OsSystem, SystemPathBuf};
+use ruff_python_ast::name::Name;
+use ty_project::metadata::options::{EnvironmentOptions, Options};
+use ty_project::{ProjectDatabase, ProjectMetadata};
+
+use crate::{
+    ExitStatus,
+    args::{ConfigArguments, SymbolsArgs},
+    resolve::resolve,
+};
+
+use self::environment::discover_environment;
+use self::error::ProjectSetupError;
+use self::manifest::{load_manifest, project_metadata_from_manifest};
+use self::output::print_symbols;
+
+mod environment;
+mod error;
+mod interpreter;
+mod manifest;
+mod output;
+
+pub(crate) fn symbols(
+    cli: &SymbolsArgs,
+    config_arguments: &ConfigArguments,
+) -> Result<ExitStatus> {
+    // Project setup failures are handled explicitly (rather than via `?`
+    // into `anyhow::Error`) so the CLI can report a remediation hint and
+    // exit with `ExitStatus::Error` instead of an opaque backtrace.
+    let pyproject_config = match resolve(config_arguments, None) {
+        Ok(config) => config,
+        Err(error) => {
+            return Ok(ProjectSetupError::ConfigResolutionFailure {
+                reason: error.to_string(),
+            }
+            .report());
+        }
+    };
+    let project_root = pyproject_config.settings.file_resolver.project_root.clone();
+    let workspace_root = match SystemPathBuf::from_path_buf(project_root.clone()) {
+        Ok(root) if project_root.is_absolute() && project_root.exists() => root,
+        _ => {
+            return Ok(ProjectSetupError::InvalidProjectRoot { path: project_root }.report());
+        }
+    };
+
+    // A `ty-project.json` manifest, when present, fully describes the
+    // project's source roots and takes priority over auto-discovery —
+    // this is what lets build systems like Bazel or Pants hand `symbols`
+    // an exact file list instead of relying on inference.
+    let manifest = match load_manifest(workspace_root.as_std_path()) {
+        Ok(manifest) => manifest,
+        Err(error) => return Ok(error.report()),
+    };
+
+    let mut project_metadata = match &manifest {
+        Some(manifest) => {
+            match project_metadata_from_manifest(manifest, workspace_root.as_std_path()) {
+                Ok(metadata) => metadata,
+                Err(error) => return Ok(error.report()),
+            }
+        }
+        None => ProjectMetadata::new(Name::new("ruff"), workspace_root.clone()),
+    };
+
+    // Extend the project with the active Python environment's stdlib and
+    // site-packages so that symbol search isn't limited to first-party
+    // sources. Falls back to the bundled typeshed stubs when
+    // auto-discovery finds no interpreter; a requested interpreter that
+    // is missing, or one that cannot be probed, is reported as an error.
+    let environment = match discover_environment(workspace_root.as_std_path(), cli.python.as_deref())
+    {
+        Ok(environment) => environment,
+        Err(error) => return Ok(error.report()),
+    };
+
+    // Extra search paths are `ty_project` configuration, applied through
+    // `Options` with `apply_cli_options` rather than a dedicated setter on
+    // `ProjectMetadata`. A manifest's own `search_paths` are layered on top
+    // of whatever the Python environment contributed.
+    let mut extra_paths = environment
+        .map(|environment| environment.extra_paths())
+        .unwrap_or_default();
+    if let Some(manifest) = &manifest {
+        extra_paths.extend(
+            manifest
+                .search_paths
+                .iter()
+                .filter_map(|path| SystemPathBuf::from_path_buf(path.clone()).ok()),
+        );
+    }
+
+    if !extra_paths.is_empty() {
+        project_metadata.apply_cli_options(Options {
+            environment: Some(EnvironmentOptions {
+                extra_paths: Some(extra_paths),
+                ..EnvironmentOptions::default()
+            }),
+            ..Options::default()
+        });
+    }
+
+    let system = OsSystem::new(&workspace_root);
+    let db = ProjectDatabase::new(project_metadata, system)?;
+
+    let symbols = ty_ide::workspace_symbols(&db, &cli.query);
+
+    print_symbols(&db, symbols, &cli.kinds, cli.limit, cli.format)?;
+
+    Ok(ExitStatus::Success)
+}
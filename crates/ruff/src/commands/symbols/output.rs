@@ -0,0 +1,209 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use ruff_db::source::source_text;
+use ruff_source_file::LineIndex;
+use ty_project::ProjectDatabase;
+
+use crate::args::{SymbolKindFilter, SymbolsFormat};
+
+/// One LSP-shaped `workspace/symbol` result: `{name, kind, uri, range}`,
+/// with 1-based line/column numbers derived from the symbol's
+/// `name_range`.
+///
+/// LSP's `workspace/symbol` also carries a `containerName` (the enclosing
+/// class or module), but `ty_ide::SymbolInfo` doesn't track an enclosing
+/// symbol, so there's nothing honest to put there — it's left out of
+/// this shape rather than shipped as a field that's always `null`.
+#[derive(Debug, Serialize)]
+struct SymbolResult {
+    name: String,
+    kind: String,
+    uri: String,
+    range: Range,
+}
+
+#[derive(Debug, Serialize)]
+struct Range {
+    start: Position,
+    end: Position,
+}
+
+#[derive(Debug, Serialize)]
+struct Position {
+    line: u32,
+    character: u32,
+}
+
+/// Print `symbols` in the format requested by `--format`, after applying
+/// `--kind` filtering and `--limit`.
+pub(crate) fn print_symbols(
+    db: &ProjectDatabase,
+    symbols: Vec<ty_ide::SymbolInfo>,
+    kinds: &[SymbolKindFilter],
+    limit: Option<usize>,
+    format: SymbolsFormat,
+) -> Result<()> {
+    let filtered = symbols
+        .into_iter()
+        .filter(|symbol| kinds.is_empty() || kinds.iter().any(|kind| matches_kind(&symbol.symbol.kind, *kind)));
+    let limited: Vec<_> = apply_limit(filtered, limit);
+
+    match format {
+        SymbolsFormat::Human => print_human(db, &limited),
+        SymbolsFormat::Json => print_json(db, &limited)?,
+    }
+
+    Ok(())
+}
+
+/// Whether a symbol of kind `kind` matches `--kind filter`.
+///
+/// Written as a real `match` with no wildcard arm, so adding a
+/// `ty_ide::SymbolKind` variant without updating this function is a
+/// compile error here rather than a `--kind` that silently stops
+/// matching anything.
+fn matches_kind(kind: &ty_ide::SymbolKind, filter: SymbolKindFilter) -> bool {
+    match (kind, filter) {
+        (ty_ide::SymbolKind::Function, SymbolKindFilter::Function)
+        | (ty_ide::SymbolKind::Method, SymbolKindFilter::Method)
+        | (ty_ide::SymbolKind::Class, SymbolKindFilter::Class)
+        | (ty_ide::SymbolKind::Variable, SymbolKindFilter::Variable)
+        | (ty_ide::SymbolKind::Constant, SymbolKindFilter::Constant)
+        | (ty_ide::SymbolKind::Module, SymbolKindFilter::Module) => true,
+        (ty_ide::SymbolKind::Function, _)
+        | (ty_ide::SymbolKind::Method, _)
+        | (ty_ide::SymbolKind::Class, _)
+        | (ty_ide::SymbolKind::Variable, _)
+        | (ty_ide::SymbolKind::Constant, _)
+        | (ty_ide::SymbolKind::Module, _) => false,
+    }
+}
+
+/// Truncate `items` to `limit` elements, or collect them all when `limit`
+/// is `None`. Factored out of [`print_symbols`] so the limiting behavior
+/// can be tested without constructing a `ty_ide::SymbolInfo`.
+fn apply_limit<T>(items: impl Iterator<Item = T>, limit: Option<usize>) -> Vec<T> {
+    match limit {
+        Some(limit) => items.take(limit).collect(),
+        None => items.collect(),
+    }
+}
+
+fn print_human(db: &ProjectDatabase, symbols: &[ty_ide::SymbolInfo]) {
+    for symbol in symbols {
+        let position = start_position(db, symbol);
+        println!(
+            "{:?} {} at {}:{}:{}",
+            symbol.symbol.kind,
+            symbol.symbol.name,
+            symbol.file.path(db),
+            position.line,
+            position.character
+        );
+    }
+}
+
+fn print_json(db: &ProjectDatabase, symbols: &[ty_ide::SymbolInfo]) -> Result<()> {
+    let results: Vec<SymbolResult> = symbols
+        .iter()
+        .map(|symbol| {
+            let start = start_position(db, symbol);
+            let end = end_position(db, symbol);
+            SymbolResult {
+                name: symbol.symbol.name.to_string(),
+                kind: format!("{:?}", symbol.symbol.kind),
+                uri: format!("file://{}", symbol.file.path(db)),
+                range: Range { start, end },
+            }
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string(&results)?);
+
+    Ok(())
+}
+
+/// 1-based line/column of the start of `symbol.name_range`.
+fn start_position(db: &ProjectDatabase, symbol: &ty_ide::SymbolInfo) -> Position {
+    line_column(db, symbol, symbol.symbol.name_range.start())
+}
+
+/// 1-based line/column of the end of `symbol.name_range`.
+fn end_position(db: &ProjectDatabase, symbol: &ty_ide::SymbolInfo) -> Position {
+    line_column(db, symbol, symbol.symbol.name_range.end())
+}
+
+fn line_column(
+    db: &ProjectDatabase,
+    symbol: &ty_ide::SymbolInfo,
+    offset: ruff_text_size::TextSize,
+) -> Position {
+    let text = source_text(db, symbol.file);
+    let line_index = LineIndex::from_source_text(&text);
+    let source_location = line_index.line_column(offset, &text);
+
+    Position {
+        line: source_location.line.get() as u32,
+        character: source_location.column.get() as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_KINDS: [ty_ide::SymbolKind; 6] = [
+        ty_ide::SymbolKind::Function,
+        ty_ide::SymbolKind::Method,
+        ty_ide::SymbolKind::Class,
+        ty_ide::SymbolKind::Variable,
+        ty_ide::SymbolKind::Constant,
+        ty_ide::SymbolKind::Module,
+    ];
+    const ALL_FILTERS: [SymbolKindFilter; 6] = [
+        SymbolKindFilter::Function,
+        SymbolKindFilter::Method,
+        SymbolKindFilter::Class,
+        SymbolKindFilter::Variable,
+        SymbolKindFilter::Constant,
+        SymbolKindFilter::Module,
+    ];
+
+    #[test]
+    fn matches_kind_only_matches_its_own_filter() {
+        for (kind_index, kind) in ALL_KINDS.iter().enumerate() {
+            for (filter_index, filter) in ALL_FILTERS.iter().enumerate() {
+                assert_eq!(
+                    matches_kind(kind, *filter),
+                    kind_index == filter_index,
+                    "{kind:?} vs {filter:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn apply_limit_none_returns_everything() {
+        let items = vec![1, 2, 3];
+        assert_eq!(apply_limit(items.into_iter(), None), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn apply_limit_truncates_to_limit() {
+        let items = vec![1, 2, 3, 4];
+        assert_eq!(apply_limit(items.into_iter(), Some(2)), vec![1, 2]);
+    }
+
+    #[test]
+    fn apply_limit_larger_than_input_returns_everything() {
+        let items = vec![1, 2];
+        assert_eq!(apply_limit(items.into_iter(), Some(10)), vec![1, 2]);
+    }
+
+    #[test]
+    fn apply_limit_zero_returns_nothing() {
+        let items = vec![1, 2, 3];
+        assert_eq!(apply_limit(items.into_iter(), Some(0)), Vec::<i32>::new());
+    }
+}